@@ -6,14 +6,12 @@ fn main() -> Result<()> {
 
     println!("Puzzle 1 answer: {}", part_1(&input));
 
-    println!("Puzzle 2 answer: ");
-    // Prints stuff out to the screen, no need to return it
-    part_2(&input);
+    println!("Puzzle 2 answer:\n{}", part_2(&input));
 
     Ok(())
 }
 
-fn part_2(input: &str) {
+fn part_2(input: &str) -> String {
     let mut crt_screen: Vec<Vec<char>> = vec![vec!['.'; 40]; 6];
     let timeline = &register_timeline(instructions(input).as_slice())[1..]; // Remove the first 1
 
@@ -29,10 +27,12 @@ fn part_2(input: &str) {
         };
     }
 
-    // Print the crt screen
-    for line in crt_screen {
-        println!("{}", line.iter().collect::<String>());
-    }
+    // Render the crt screen, one line per row
+    crt_screen
+        .iter()
+        .map(|line| line.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 fn part_1(input: &str) -> i64 {
@@ -8,7 +8,7 @@ fn main() {
 }
 
 #[solution(day = "21", part = "2")]
-fn part_2(input: &str) -> f64 {
+fn part_2(input: &str) -> i64 {
     let mut monkeys = monkeys(input);
     monkeys.insert("humn".into(), Monkey::Number(Complex64::new(0.0, 1.0)));
 
@@ -24,14 +24,14 @@ fn part_2(input: &str) -> f64 {
         swap(&mut left, &mut right);
     }
 
-    ((right.re - left.re) / left.im).round()
+    ((right.re - left.re) / left.im).round() as i64
 }
 
 #[solution(day = "21", part = "1")]
-fn part_1(input: &str) -> f64 {
+fn part_1(input: &str) -> i64 {
     let monkeys = monkeys(input);
 
-    eval_monkeys(&monkeys, "root").re
+    eval_monkeys(&monkeys, "root").re.round() as i64
 }
 
 fn eval_monkeys(monkeys: &HashMap<String, Monkey>, id: &str) -> Complex64 {